@@ -10,7 +10,7 @@ mod wormhole;
 use napi_derive::napi;
 
 // Re-export types for JavaScript
-pub use types::{ProgressEvent, ReceiveOffer};
+pub use types::{OfferEntry, ProgressEvent, ReceiveOffer, TransitEvent, WormholeOptions};
 pub use wormhole::WormholeClient;
 
 /// Hello world test function to verify napi-rs setup
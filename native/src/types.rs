@@ -29,21 +29,91 @@ impl ProgressEvent {
     }
 }
 
+/// Options for configuring the rendezvous and relay servers a `WormholeClient` uses
+#[napi(object)]
+#[derive(Debug, Clone, Default)]
+pub struct WormholeOptions {
+    /// URL of the rendezvous (mailbox) server, e.g. `ws://my-server:4000/v1`
+    pub rendezvous_url: Option<String>,
+    /// URLs of transit relay servers to offer as hints
+    pub relay_urls: Option<Vec<String>>,
+    /// Application ID to negotiate with peers, for running isolated deployments
+    pub app_id: Option<String>,
+}
+
+/// Metadata describing a single file or directory within a transfer offer
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct OfferEntry {
+    /// Name of the entry, relative to the offer root
+    pub name: String,
+    /// Size of the entry in bytes
+    pub size: i64,
+    /// Whether this entry is a directory
+    pub is_directory: bool,
+}
+
+impl OfferEntry {
+    pub fn new(name: String, size: u64, is_directory: bool) -> Self {
+        Self {
+            name,
+            size: size as i64,
+            is_directory,
+        }
+    }
+}
+
+/// Reports whether a transfer's transit connection is a direct peer-to-peer
+/// link or went through a relay server
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct TransitEvent {
+    /// `"direct"` for a peer-to-peer connection, `"relay"` when relayed
+    pub kind: String,
+    /// Address of the peer or relay, if one could be determined
+    pub peer_addr: Option<String>,
+}
+
 /// Information about an incoming file transfer offer
 #[napi(object)]
 #[derive(Debug, Clone)]
 pub struct ReceiveOffer {
-    /// Name of the file being sent
+    /// `"file"` for a file/folder offer, `"text"` for a short text message
+    pub kind: String,
+    /// Name of the file being sent, or of the combined offer for multi-file transfers
     pub filename: String,
-    /// Size of the file in bytes
+    /// Combined size of the offer in bytes
     pub filesize: i64,
+    /// Entry metadata for the offer; `connectReceive()` reports a single entry
+    /// named and sized as the whole offer, with `is_directory` false, since the
+    /// contents of a folder or multi-file offer are not listed before accepting
+    pub entries: Vec<OfferEntry>,
+    /// Always `None` when the offer is reported; a text offer's body is
+    /// returned by `accept_text()`
+    pub text: Option<String>,
 }
 
 impl ReceiveOffer {
-    pub fn new(filename: String, filesize: u64) -> Self {
+    pub fn new_file(filename: String, filesize: u64, entries: Vec<OfferEntry>) -> Self {
         Self {
+            kind: "file".to_string(),
             filename,
             filesize: filesize as i64,
+            entries,
+            text: None,
+        }
+    }
+
+    /// A text message offer that's been seen but not yet accepted - the
+    /// body isn't known until `accept_text()` actually downloads it, so
+    /// `text` stays `None` here.
+    pub fn new_text_pending(filesize: u64) -> Self {
+        Self {
+            kind: "text".to_string(),
+            filename: String::new(),
+            filesize: filesize as i64,
+            entries: Vec::new(),
+            text: None,
         }
     }
 }
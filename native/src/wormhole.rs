@@ -3,23 +3,32 @@
 //! Provides async file transfer capabilities via the Magic Wormhole protocol.
 
 use crate::error::WormholeError;
-use crate::types::{ProgressEvent, ReceiveOffer};
+use crate::types::{OfferEntry, ProgressEvent, ReceiveOffer, TransitEvent, WormholeOptions};
 use napi::bindgen_prelude::*;
 use napi::threadsafe_function::{ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use tokio::sync::{oneshot, Mutex};
 
 use magic_wormhole::{
-    transfer::{self, ReceiveRequestV1, AppVersion},
-    transit::{Abilities, RelayHint, TransitInfo},
-    MailboxConnection, Wormhole,
+    transfer::{self, AppVersion, ReceiveRequestV1},
+    transit::{Abilities, ConnectionType, RelayHint, TransitInfo},
+    AppConfig, AppID, MailboxConnection, Wormhole,
 };
 
-/// Default relay server URL
+/// Default relay server URL, used when no `relay_urls` override is configured
 const DEFAULT_RELAY_SERVER: &str = "wss://relay.magic-wormhole.io:443/v1";
 
+/// Filename a text message is staged and sent under
+///
+/// The crate's default (non-experimental) transfer protocol has no
+/// dedicated "message" offer type - every offer is a file or folder - so a
+/// text send is staged as a small file under this conventional name and
+/// detected on the receive side by filename, rather than by offer variant.
+const TEXT_MESSAGE_FILENAME: &str = "wormhole-text-message.txt";
+
 /// Internal state for an active wormhole session
 enum SessionState {
     /// No active session
@@ -45,18 +54,155 @@ enum SessionState {
 #[napi]
 pub struct WormholeClient {
     state: Arc<Mutex<SessionState>>,
+    rendezvous_url: Option<String>,
+    relay_urls: Vec<String>,
+    app_id: Option<String>,
+    /// Fires to interrupt the transfer step currently in flight (`transfer::send`,
+    /// `request_file` or `accept`); it is armed only after `Wormhole::connect`
+    /// returns, so the mailbox connection and PAKE wait are not interrupted
+    cancel_tx: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    /// Set just before `cancel_tx` fires, so the in-flight operation can tell
+    /// a user-initiated cancellation apart from any other failure
+    cancelled: Arc<AtomicBool>,
+    /// Hex-encoded SPAKE2 verifier for the current session, once connected
+    verifier: Arc<Mutex<Option<String>>>,
 }
 
 #[napi]
 impl WormholeClient {
     /// Create a new WormholeClient instance
+    ///
+    /// By default this talks to the public `relay.magic-wormhole.io`
+    /// infrastructure. Pass `options` to point it at self-hosted rendezvous
+    /// and/or relay servers instead.
     #[napi(constructor)]
-    pub fn new() -> Self {
+    pub fn new(options: Option<WormholeOptions>) -> Self {
+        let options = options.unwrap_or_default();
         Self {
             state: Arc::new(Mutex::new(SessionState::Idle)),
+            rendezvous_url: options.rendezvous_url,
+            relay_urls: options.relay_urls.unwrap_or_default(),
+            app_id: options.app_id,
+            cancel_tx: Arc::new(Mutex::new(None)),
+            cancelled: Arc::new(AtomicBool::new(false)),
+            verifier: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Return the SPAKE2 verifier for the current session as a hex string,
+    /// once the PAKE exchange with the peer has completed
+    ///
+    /// Both sides can compare this value over an out-of-band channel (e.g. a
+    /// phone call) to detect a man-in-the-middle on the wormhole code.
+    #[napi]
+    pub async fn get_verifier(&self) -> Option<String> {
+        self.verifier.lock().await.clone()
+    }
+
+    /// Record the verifier for the session that was just established
+    async fn store_verifier(&self, wormhole: &Wormhole) {
+        *self.verifier.lock().await = Some(to_hex(&wormhole.verifier()));
+    }
+
+    /// Arm cancellation for the operation about to start, returning a future
+    /// that resolves once `cancel()` is called
+    async fn arm_cancel(&self) -> impl std::future::Future<Output = ()> {
+        self.cancelled.store(false, Ordering::SeqCst);
+        let (tx, rx) = oneshot::channel();
+        *self.cancel_tx.lock().await = Some(tx);
+        async move {
+            let _ = rx.await;
+        }
+    }
+
+    /// Classify a `TransitInfo` into the kind of connection that was
+    /// established, for reporting to the UI
+    fn transit_event(info: &TransitInfo) -> TransitEvent {
+        let kind = match info.conn_type {
+            ConnectionType::Direct => "direct",
+            ConnectionType::Relay { .. } => "relay",
+            _ => "unknown",
+        };
+        TransitEvent {
+            kind: kind.to_string(),
+            peer_addr: Some(info.peer_addr.to_string()),
         }
     }
 
+    /// Map a transfer-crate error to `Cancelled` if it happened because
+    /// `cancel()` was called, otherwise to a generic `TransferFailed`
+    fn to_transfer_error<E: std::fmt::Display>(&self, err: E) -> WormholeError {
+        if self.cancelled.load(Ordering::SeqCst) {
+            WormholeError::Cancelled
+        } else {
+            WormholeError::TransferFailed(err.to_string())
+        }
+    }
+
+    /// Drive a transfer-crate future on its own task so that a panic
+    /// triggered by a misbehaving or version-incompatible peer surfaces as a
+    /// typed `ProtocolError` instead of aborting the whole Node process
+    async fn run_transfer<F, T, E>(&self, fut: F) -> Result<T>
+    where
+        F: std::future::Future<Output = std::result::Result<T, E>> + Send + 'static,
+        T: Send + 'static,
+        E: std::fmt::Display + Send,
+    {
+        match tokio::spawn(fut).await {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(e)) => Err(self.to_transfer_error(e).into()),
+            Err(join_err) => {
+                Err(WormholeError::ProtocolError(format!("peer transfer task panicked: {}", join_err)).into())
+            }
+        }
+    }
+
+    /// Confirm the peer negotiated a compatible app version, converting an
+    /// incompatible peer into a typed error instead of letting the mismatch
+    /// surface deeper in the transfer state machine
+    ///
+    /// App version/ability negotiation happens as part of the PAKE handshake
+    /// (`Wormhole::connect`), so this must run after that succeeds - nothing
+    /// has been exchanged with the peer before then.
+    fn ensure_compatible_peer(wormhole: &Wormhole) -> std::result::Result<(), WormholeError> {
+        if wormhole.peer_version().is_null() {
+            return Err(WormholeError::ProtocolError(
+                "peer did not negotiate a compatible app version".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Build the app config for this session, applying any configured
+    /// rendezvous URL and app ID override
+    fn app_config(&self) -> AppConfig<AppVersion> {
+        let mut config = transfer::APP_CONFIG;
+        if let Some(rendezvous_url) = &self.rendezvous_url {
+            config = config.rendezvous_url(rendezvous_url.clone().into());
+        }
+        if let Some(app_id) = &self.app_id {
+            config = config.id(AppID::new(app_id.clone()));
+        }
+        config
+    }
+
+    /// Build the relay hints for this session, falling back to the default
+    /// public relay when none are configured
+    fn relay_hints(&self) -> std::result::Result<Vec<RelayHint>, WormholeError> {
+        let urls: Vec<&str> = if self.relay_urls.is_empty() {
+            vec![DEFAULT_RELAY_SERVER]
+        } else {
+            self.relay_urls.iter().map(String::as_str).collect()
+        };
+        let parsed = urls
+            .into_iter()
+            .map(|url| url.parse().map_err(|_| WormholeError::ConnectionFailed(format!("invalid relay url: {}", url))))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let hint = RelayHint::from_urls(None, parsed)
+            .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?;
+        Ok(vec![hint])
+    }
+
     /// Generate a wormhole code for sending a file
     /// Returns the code immediately after connecting to the mailbox server.
     /// The actual PAKE exchange happens when sendFile() is called.
@@ -65,11 +211,13 @@ impl WormholeClient {
         let code_length = code_length.unwrap_or(2) as usize;
 
         // Create relay hints
-        let relay_hints = vec![RelayHint::from_urls(None, [DEFAULT_RELAY_SERVER.parse().unwrap()])
-            .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?];
+        let relay_hints = self.relay_hints()?;
+
+        // A new session has no verifier until its PAKE exchange completes
+        *self.verifier.lock().await = None;
 
         // Connect to mailbox server and allocate code
-        let mailbox = MailboxConnection::create(transfer::APP_CONFIG, code_length)
+        let mailbox = MailboxConnection::create(self.app_config(), code_length)
             .await
             .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?;
 
@@ -95,6 +243,8 @@ impl WormholeClient {
         file_path: String,
         #[napi(ts_arg_type = "(err: null | Error, progress: ProgressEvent) => void")]
         progress_callback: ThreadsafeFunction<ProgressEvent>,
+        #[napi(ts_arg_type = "(event: TransitEvent) => void")]
+        transit_callback: Option<ThreadsafeFunction<TransitEvent>>,
     ) -> Result<()> {
         let path = PathBuf::from(&file_path);
 
@@ -122,6 +272,9 @@ impl WormholeClient {
             .await
             .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?;
 
+        Self::ensure_compatible_peer(&wormhole)?;
+        self.store_verifier(&wormhole).await;
+
         // Get file name
         let file_name = path
             .file_name()
@@ -137,9 +290,15 @@ impl WormholeClient {
         // Get total size for progress
         let total_size = offer.total_size();
 
-        // Transit handler (logs connection info)
-        let transit_handler = |info: TransitInfo| {
+        // Transit handler - logs connection info and reports it to the UI
+        let transit_handler = move |info: TransitInfo| {
             tracing::info!("Transit: {}", info);
+            if let Some(cb) = &transit_callback {
+                cb.call(
+                    Ok(Self::transit_event(&info)),
+                    ThreadsafeFunctionCallMode::NonBlocking,
+                );
+            }
         };
 
         // Progress handler - use Arc to share callback
@@ -150,18 +309,141 @@ impl WormholeClient {
             progress_cb_clone.call(Ok(event), ThreadsafeFunctionCallMode::NonBlocking);
         };
 
-        // Send the file
-        transfer::send(
-            wormhole,
-            relay_hints,
-            Abilities::ALL,
-            offer,
-            &transit_handler,
-            progress_handler,
-            futures::future::pending::<()>(), // No cancellation for now
-        )
-        .await
-        .map_err(|e| WormholeError::TransferFailed(e.to_string()))?;
+        // Send the file, on its own task so a panicking peer can't take the whole process down
+        let cancel = self.arm_cancel().await;
+        self.run_transfer(async move {
+            transfer::send(
+                wormhole,
+                relay_hints,
+                Abilities::ALL,
+                offer,
+                &transit_handler,
+                progress_handler,
+                cancel,
+            )
+            .await
+        })
+        .await?;
+
+        // Send 100% completion
+        progress_cb.call(
+            Ok(ProgressEvent::new(total_size, total_size)),
+            ThreadsafeFunctionCallMode::NonBlocking,
+        );
+
+        Ok(())
+    }
+
+    /// Send one or more files and/or folders as a single combined offer
+    ///
+    /// A single path is sent as-is (the transfer crate tars folders for us).
+    /// Multiple paths are staged into a temporary directory first so the
+    /// receiver still only ever sees one offer, with its aggregate size
+    /// reported for progress.
+    #[napi]
+    pub async fn send_files(
+        &self,
+        paths: Vec<String>,
+        #[napi(ts_arg_type = "(err: null | Error, progress: ProgressEvent) => void")]
+        progress_callback: ThreadsafeFunction<ProgressEvent>,
+        #[napi(ts_arg_type = "(event: TransitEvent) => void")]
+        transit_callback: Option<ThreadsafeFunction<TransitEvent>>,
+    ) -> Result<()> {
+        if paths.is_empty() {
+            return Err(WormholeError::TransferFailed("no files selected".to_string()).into());
+        }
+
+        let mut sources = Vec::with_capacity(paths.len());
+        for raw in &paths {
+            let path = PathBuf::from(raw);
+            if !path.exists() {
+                return Err(WormholeError::FileNotFound(raw.clone()).into());
+            }
+            sources.push(path);
+        }
+
+        // Take ownership of mailbox from state
+        let (mailbox, relay_hints) = {
+            let mut state = self.state.lock().await;
+            match std::mem::replace(&mut *state, SessionState::Idle) {
+                SessionState::MailboxReady {
+                    mailbox,
+                    relay_hints,
+                } => (mailbox, relay_hints),
+                _ => return Err(WormholeError::NoActiveSession.into()),
+            }
+        };
+
+        // Now do the PAKE exchange - this waits for the receiver
+        let wormhole = Wormhole::connect(mailbox)
+            .await
+            .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?;
+
+        Self::ensure_compatible_peer(&wormhole)?;
+        self.store_verifier(&wormhole).await;
+
+        // Single path: send it as-is. Multiple paths: stage into one temp
+        // directory so they travel as a single tarred folder offer.
+        let (offer_name, offer_path, _staging) = if sources.len() == 1 {
+            let path = sources.into_iter().next().unwrap();
+            let name = entry_name(&path);
+            (name, path, None)
+        } else {
+            let staging = tempfile::tempdir()?;
+            let dest_names = disambiguate_entry_names(&sources);
+            for (path, dest_name) in sources.iter().zip(dest_names) {
+                let dest = staging.path().join(dest_name);
+                if path.is_dir() {
+                    copy_dir_all(path, &dest)?;
+                } else {
+                    std::fs::copy(path, &dest)?;
+                }
+            }
+            ("files".to_string(), staging.path().to_path_buf(), Some(staging))
+        };
+
+        // Create the offer
+        let offer = transfer::offer::OfferSend::new_file_or_folder(offer_name, offer_path)
+            .await
+            .map_err(|e| WormholeError::TransferFailed(e.to_string()))?;
+
+        // Get total size for progress
+        let total_size = offer.total_size();
+
+        // Transit handler - logs connection info and reports it to the UI
+        let transit_handler = move |info: TransitInfo| {
+            tracing::info!("Transit: {}", info);
+            if let Some(cb) = &transit_callback {
+                cb.call(
+                    Ok(Self::transit_event(&info)),
+                    ThreadsafeFunctionCallMode::NonBlocking,
+                );
+            }
+        };
+
+        // Progress handler - use Arc to share callback
+        let progress_cb = Arc::new(progress_callback);
+        let progress_cb_clone = progress_cb.clone();
+        let progress_handler = move |sent: u64, total: u64| {
+            let event = ProgressEvent::new(sent, total);
+            progress_cb_clone.call(Ok(event), ThreadsafeFunctionCallMode::NonBlocking);
+        };
+
+        // Send the combined offer, on its own task so a panicking peer can't take the whole process down
+        let cancel = self.arm_cancel().await;
+        self.run_transfer(async move {
+            transfer::send(
+                wormhole,
+                relay_hints,
+                Abilities::ALL,
+                offer,
+                &transit_handler,
+                progress_handler,
+                cancel,
+            )
+            .await
+        })
+        .await?;
 
         // Send 100% completion
         progress_cb.call(
@@ -172,6 +454,65 @@ impl WormholeClient {
         Ok(())
     }
 
+    /// Send a short text message instead of a file
+    /// Reuses the same mailbox/PAKE plumbing as `sendFile`, staging the
+    /// message as a small file under `TEXT_MESSAGE_FILENAME` since the
+    /// crate has no dedicated message-offer type to send instead.
+    #[napi]
+    pub async fn send_text(&self, message: String) -> Result<()> {
+        // Take ownership of mailbox from state
+        let (mailbox, relay_hints) = {
+            let mut state = self.state.lock().await;
+            match std::mem::replace(&mut *state, SessionState::Idle) {
+                SessionState::MailboxReady {
+                    mailbox,
+                    relay_hints,
+                } => (mailbox, relay_hints),
+                _ => return Err(WormholeError::NoActiveSession.into()),
+            }
+        };
+
+        // Now do the PAKE exchange - this waits for the receiver
+        let wormhole = Wormhole::connect(mailbox)
+            .await
+            .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?;
+
+        Self::ensure_compatible_peer(&wormhole)?;
+        self.store_verifier(&wormhole).await;
+
+        let staging = tempfile::NamedTempFile::new()?;
+        std::fs::write(staging.path(), message.as_bytes())?;
+        let offer = transfer::offer::OfferSend::new_file_or_folder(
+            TEXT_MESSAGE_FILENAME.to_string(),
+            staging.path().to_path_buf(),
+        )
+        .await
+        .map_err(|e| WormholeError::TransferFailed(e.to_string()))?;
+
+        let transit_handler = |info: TransitInfo| {
+            tracing::info!("Transit: {}", info);
+        };
+
+        let cancel = self.arm_cancel().await;
+        self.run_transfer(async move {
+            // Keep the staging file alive until the send is done reading it
+            let _staging = staging;
+            transfer::send(
+                wormhole,
+                relay_hints,
+                Abilities::ALL,
+                offer,
+                &transit_handler,
+                |_sent: u64, _total: u64| {},
+                cancel,
+            )
+            .await
+        })
+        .await?;
+
+        Ok(())
+    }
+
     /// Connect to receive a file using the given code
     /// Returns information about the offered file
     #[napi]
@@ -182,11 +523,13 @@ impl WormholeClient {
             .map_err(|_| WormholeError::InvalidCode(code.clone()))?;
 
         // Create relay hints
-        let relay_hints = vec![RelayHint::from_urls(None, [DEFAULT_RELAY_SERVER.parse().unwrap()])
-            .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?];
+        let relay_hints = self.relay_hints()?;
+
+        // A new session has no verifier until its PAKE exchange completes
+        *self.verifier.lock().await = None;
 
         // Connect to mailbox
-        let mailbox = MailboxConnection::connect(transfer::APP_CONFIG, wormhole_code, true)
+        let mailbox = MailboxConnection::connect(self.app_config(), wormhole_code, true)
             .await
             .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?;
 
@@ -195,23 +538,47 @@ impl WormholeClient {
             .await
             .map_err(|e| WormholeError::ConnectionFailed(e.to_string()))?;
 
-        // Request file transfer
-        let request = transfer::request_file(
-            wormhole,
-            relay_hints.clone(),
-            Abilities::ALL,
-            futures::future::pending::<()>(), // No cancellation for now
-        )
-        .await
-        .map_err(|e| WormholeError::TransferFailed(e.to_string()))?;
+        Self::ensure_compatible_peer(&wormhole)?;
+        self.store_verifier(&wormhole).await;
+
+        // Request file transfer, on its own task so a panicking peer can't take the whole process down
+        let cancel = self.arm_cancel().await;
+        let relay_hints_for_request = relay_hints.clone();
+        let request = self
+            .run_transfer(async move {
+                transfer::request_file(wormhole, relay_hints_for_request, Abilities::ALL, cancel).await
+            })
+            .await?;
 
         // Handle None case (cancelled)
         let request = request.ok_or_else(|| WormholeError::Cancelled)?;
 
+        // A text message was staged under TEXT_MESSAGE_FILENAME by the
+        // sender (see `send_text`) - there's no way to read its body before
+        // accepting, so report the offer as pending text and let
+        // `accept_text()` do the actual accept/decode.
+        if request.file_name() == TEXT_MESSAGE_FILENAME {
+            let filesize = request.file_size();
+            let mut state = self.state.lock().await;
+            *state = SessionState::Receiving {
+                request,
+                relay_hints,
+            };
+            return Ok(ReceiveOffer::new_text_pending(filesize));
+        }
+
         // Extract file info
         let filename = request.file_name().to_string();
         let filesize = request.file_size();
 
+        // Outside the (unset) experimental-transfer-v2 feature, the default
+        // protocol's ReceiveRequestV1 exposes only file_name()/file_size()
+        // before accept - there's no offer manifest to inspect, so whether
+        // this is a single file or a tarred folder genuinely can't be known
+        // until accept() unpacks it. Report is_directory as unknown (false)
+        // until then rather than guessing from an API this build doesn't have.
+        let entries = vec![OfferEntry::new(filename.clone(), filesize, false)];
+
         // Store the request for accept/reject
         let mut state = self.state.lock().await;
         *state = SessionState::Receiving {
@@ -219,7 +586,7 @@ impl WormholeClient {
             relay_hints,
         };
 
-        Ok(ReceiveOffer::new(filename, filesize))
+        Ok(ReceiveOffer::new_file(filename, filesize, entries))
     }
 
     /// Accept the incoming file transfer
@@ -230,6 +597,8 @@ impl WormholeClient {
         output_dir: String,
         #[napi(ts_arg_type = "(err: null | Error, progress: ProgressEvent) => void")]
         progress_callback: ThreadsafeFunction<ProgressEvent>,
+        #[napi(ts_arg_type = "(event: TransitEvent) => void")]
+        transit_callback: Option<ThreadsafeFunction<TransitEvent>>,
     ) -> Result<String> {
         // Take ownership of request from state
         let (request, _relay_hints) = {
@@ -243,6 +612,13 @@ impl WormholeClient {
             }
         };
 
+        if request.file_name() == TEXT_MESSAGE_FILENAME {
+            return Err(WormholeError::TransferFailed(
+                "offer is a text message, use acceptText() instead".to_string(),
+            )
+            .into());
+        }
+
         let filename = request.file_name().to_string();
         let filesize = request.file_size();
         let output_path = PathBuf::from(&output_dir).join(&filename);
@@ -256,9 +632,15 @@ impl WormholeClient {
             .await
             .map_err(|e| WormholeError::IoError(e.to_string()))?;
 
-        // Transit handler
-        let transit_handler = |info: TransitInfo| {
+        // Transit handler - logs connection info and reports it to the UI
+        let transit_handler = move |info: TransitInfo| {
             tracing::info!("Transit: {}", info);
+            if let Some(cb) = &transit_callback {
+                cb.call(
+                    Ok(Self::transit_event(&info)),
+                    ThreadsafeFunctionCallMode::NonBlocking,
+                );
+            }
         };
 
         // Progress handler - use Arc to share callback
@@ -269,16 +651,21 @@ impl WormholeClient {
             progress_cb_clone.call(Ok(event), ThreadsafeFunctionCallMode::NonBlocking);
         };
 
-        // Accept and receive the file
-        request
-            .accept(
-                &transit_handler,
-                progress_handler,
-                &mut file,
-                futures::future::pending::<()>(), // No cancellation for now
-            )
+        // Accept and receive the file, on its own task so a panicking peer can't take the whole process down
+        let cancel = self.arm_cancel().await;
+        if let Err(e) = self
+            .run_transfer(async move {
+                request
+                    .accept(&transit_handler, progress_handler, &mut file, cancel)
+                    .await
+            })
             .await
-            .map_err(|e| WormholeError::TransferFailed(e.to_string()))?;
+        {
+            // Don't leave a half-written file behind, whether the transfer
+            // was cancelled or simply failed partway through
+            let _ = async_std::fs::remove_file(&output_path).await;
+            return Err(e);
+        }
 
         // Send 100% completion
         progress_cb.call(
@@ -289,6 +676,46 @@ impl WormholeClient {
         Ok(output_path.to_string_lossy().to_string())
     }
 
+    /// Accept an incoming text message offer, returning its body
+    #[napi]
+    pub async fn accept_text(&self) -> Result<String> {
+        // Take ownership of request from state
+        let (request, _relay_hints) = {
+            let mut state = self.state.lock().await;
+            match std::mem::replace(&mut *state, SessionState::Idle) {
+                SessionState::Receiving {
+                    request,
+                    relay_hints,
+                } => (request, relay_hints),
+                _ => return Err(WormholeError::NoActiveSession.into()),
+            }
+        };
+
+        if request.file_name() != TEXT_MESSAGE_FILENAME {
+            return Err(WormholeError::TransferFailed(
+                "offer is not a text message, use acceptTransfer() instead".to_string(),
+            )
+            .into());
+        }
+
+        let transit_handler = |info: TransitInfo| {
+            tracing::info!("Transit: {}", info);
+        };
+
+        let cancel = self.arm_cancel().await;
+        let bytes = self
+            .run_transfer(async move {
+                let mut sink = async_std::io::Cursor::new(Vec::new());
+                request
+                    .accept(&transit_handler, |_, _| {}, &mut sink, cancel)
+                    .await
+                    .map(|_| sink.into_inner())
+            })
+            .await?;
+
+        Ok(String::from_utf8_lossy(&bytes).into_owned())
+    }
+
     /// Reject the incoming file transfer
     #[napi]
     pub async fn reject_transfer(&self) -> Result<()> {
@@ -308,10 +735,13 @@ impl WormholeClient {
     /// Cancel any active operation
     #[napi]
     pub fn cancel(&self) {
-        // For now, just reset state
-        // TODO: Implement proper cancellation with oneshot channel
+        self.cancelled.store(true, Ordering::SeqCst);
+        let cancel_tx = self.cancel_tx.clone();
         let state = self.state.clone();
         tokio::spawn(async move {
+            if let Some(tx) = cancel_tx.lock().await.take() {
+                let _ = tx.send(());
+            }
             let mut s = state.lock().await;
             *s = SessionState::Idle;
         });
@@ -320,6 +750,62 @@ impl WormholeClient {
 
 impl Default for WormholeClient {
     fn default() -> Self {
-        Self::new()
+        Self::new(None)
+    }
+}
+
+/// Hex-encode bytes, used to render the SPAKE2 verifier for display
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// File or directory name to use for a path within a combined offer
+fn entry_name(path: &std::path::Path) -> String {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("file")
+        .to_string()
+}
+
+/// Resolve each path's staging destination name, disambiguating collisions
+/// so two selected paths sharing a basename (e.g. `a/notes.txt` and
+/// `b/notes.txt`) don't silently overwrite one another when staged into the
+/// same temp directory. Only names that actually collide get a numeric
+/// prefix, so the common case of all-distinct basenames is untouched.
+fn disambiguate_entry_names(paths: &[PathBuf]) -> Vec<String> {
+    let names: Vec<String> = paths.iter().map(|path| entry_name(path)).collect();
+
+    let mut counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    for name in &names {
+        *counts.entry(name.clone()).or_insert(0) += 1;
+    }
+
+    let mut seen: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    names
+        .into_iter()
+        .map(|name| {
+            if counts[&name] <= 1 {
+                return name;
+            }
+            let index = seen.entry(name.clone()).or_insert(0);
+            *index += 1;
+            format!("{}-{}", index, name)
+        })
+        .collect()
+}
+
+/// Recursively copy a directory tree, used to stage multiple selected paths
+/// into a single temporary folder before tarring them as one offer.
+fn copy_dir_all(src: &std::path::Path, dest: &std::path::Path) -> std::result::Result<(), WormholeError> {
+    std::fs::create_dir_all(dest)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let dest_path = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &dest_path)?;
+        } else {
+            std::fs::copy(entry.path(), &dest_path)?;
+        }
     }
+    Ok(())
 }